@@ -0,0 +1,240 @@
+//! UTS #35 Annex C "LocaleId Canonicalization" support for
+//! [`Locale::canonicalize`](crate::locale::Locale::canonicalize).
+//!
+//! The alias tables here are a hand-picked subset of CLDR's
+//! `supplemental/metadata.xml` (`languageAlias`, `territoryAlias`,
+//! `scriptAlias`, `variantAlias`), covering the deprecated/grandfathered
+//! forms this crate has been asked to handle.
+
+use crate::likely_subtags;
+
+struct Alias {
+    from: &'static str,
+    to: &'static str,
+}
+
+static LANGUAGE_ALIASES: &[Alias] = &[
+    Alias {
+        from: "iw",
+        to: "he",
+    },
+    Alias {
+        from: "in",
+        to: "id",
+    },
+    Alias {
+        from: "ji",
+        to: "yi",
+    },
+    Alias {
+        from: "mo",
+        to: "ro",
+    },
+    Alias {
+        from: "tl",
+        to: "fil",
+    },
+];
+
+static SCRIPT_ALIASES: &[Alias] = &[Alias {
+    from: "Qaai",
+    to: "Zinh",
+}];
+
+/// Territory aliases that have a single, unconditional replacement.
+static TERRITORY_ALIASES: &[Alias] = &[
+    Alias {
+        from: "BU",
+        to: "MM",
+    },
+    Alias {
+        from: "CS",
+        to: "RS",
+    },
+    Alias {
+        from: "DD",
+        to: "DE",
+    },
+    Alias {
+        from: "FX",
+        to: "FR",
+    },
+    Alias {
+        from: "TP",
+        to: "TL",
+    },
+    Alias {
+        from: "YU",
+        to: "RS",
+    },
+    Alias {
+        from: "ZR",
+        to: "CD",
+    },
+];
+
+/// Territory aliases with more than one candidate replacement, resolved
+/// by picking the candidate that the locale's language maximizes to,
+/// or the first candidate if none does (see `replace_territory`).
+static TERRITORY_MULTI_ALIASES: &[(&str, &[&str])] = &[("SU", &["RU", "AM", "AZ", "GE"])];
+
+fn replace(table: &[Alias], value: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|a| a.from.eq_ignore_ascii_case(value))
+        .map(|a| a.to)
+}
+
+/// Resolves a territory alias, consulting the likely-subtags maximizer to
+/// disambiguate aliases with multiple candidate replacements.
+fn replace_territory(language: Option<&str>, value: &str) -> Option<&'static str> {
+    if let Some(to) = replace(TERRITORY_ALIASES, value) {
+        return Some(to);
+    }
+
+    let (_, candidates) = TERRITORY_MULTI_ALIASES
+        .iter()
+        .find(|(from, _)| from.eq_ignore_ascii_case(value))?;
+
+    let likely_region = likely_subtags::maximize(language, None, None).map(|(_, _, r)| r);
+
+    candidates
+        .iter()
+        .find(|candidate| likely_region.as_deref() == Some(**candidate))
+        .copied()
+        .or_else(|| candidates.first().copied())
+}
+
+pub fn canonical_language(language: &str) -> Option<&'static str> {
+    replace(LANGUAGE_ALIASES, language)
+}
+
+pub fn canonical_script(script: &str) -> Option<&'static str> {
+    replace(SCRIPT_ALIASES, script)
+}
+
+pub fn canonical_region(language: Option<&str>, region: &str) -> Option<&'static str> {
+    replace_territory(language, region)
+}
+
+/// Applies variant aliases, then sorts and dedupes the result.
+///
+/// `hepburn` + `heploc` together alias to the single variant `alalc97`
+/// (UTS #35 Annex C); a standalone `heploc` aliases to `alalc97` as well.
+pub fn canonical_variants(variants: &[String]) -> (Vec<String>, bool) {
+    let mut working: Vec<String> = variants.to_vec();
+    let mut changed = false;
+
+    let has_hepburn = working.iter().any(|v| v.eq_ignore_ascii_case("hepburn"));
+    let has_heploc = working.iter().any(|v| v.eq_ignore_ascii_case("heploc"));
+
+    if has_heploc {
+        working.retain(|v| {
+            !(v.eq_ignore_ascii_case("heploc") || (has_hepburn && v.eq_ignore_ascii_case("hepburn")))
+        });
+        working.push("alalc97".to_owned());
+        changed = true;
+    }
+
+    let before_len = working.len();
+    working.sort();
+    working.dedup();
+    if working.len() != before_len {
+        changed = true;
+    }
+    if working != variants {
+        changed = true;
+    }
+
+    (working, changed)
+}
+
+/// Sorts and dedupes the key/value groups of an extension subtag list
+/// (e.g. the subtags following `-u-` or `-t-`), preserving any leading
+/// subtags that appear before the first key (attributes for `-u-`, the
+/// `tlang` component for `-t-`).
+pub fn canonical_extension_subtags(subtags: &[String]) -> Vec<String> {
+    let mut prefix = Vec::new();
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+
+    for subtag in subtags {
+        if is_extension_key(subtag) {
+            groups.push((subtag.clone(), Vec::new()));
+        } else if let Some((_, values)) = groups.last_mut() {
+            values.push(subtag.clone());
+        } else {
+            prefix.push(subtag.clone());
+        }
+    }
+
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    groups.dedup_by(|a, b| a.0 == b.0);
+
+    let mut out = prefix;
+    for (key, values) in groups {
+        out.push(key);
+        out.extend(values);
+    }
+    out
+}
+
+fn is_extension_key(subtag: &str) -> bool {
+    subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replaces_deprecated_language() {
+        assert_eq!(canonical_language("iw"), Some("he"));
+        assert_eq!(canonical_language("en"), None);
+    }
+
+    #[test]
+    fn replaces_deprecated_territory() {
+        assert_eq!(canonical_region(None, "BU"), Some("MM"));
+    }
+
+    #[test]
+    fn sorts_and_dedupes_variants() {
+        let (variants, changed) = canonical_variants(&[
+            "1996".to_owned(),
+            "1901".to_owned(),
+            "1996".to_owned(),
+        ]);
+        assert_eq!(variants, vec!["1901".to_owned(), "1996".to_owned()]);
+        assert!(changed);
+    }
+
+    #[test]
+    fn collapses_hepburn_heploc_pair() {
+        let (variants, changed) = canonical_variants(&[
+            "fonipa".to_owned(),
+            "hepburn".to_owned(),
+            "heploc".to_owned(),
+        ]);
+        assert_eq!(variants, vec!["alalc97".to_owned(), "fonipa".to_owned()]);
+        assert!(changed);
+    }
+
+    #[test]
+    fn reorders_extension_subtags_keeping_groups_intact() {
+        let subtags = vec![
+            "ka".to_owned(),
+            "shifted".to_owned(),
+            "co".to_owned(),
+            "phonebk".to_owned(),
+        ];
+        assert_eq!(
+            canonical_extension_subtags(&subtags),
+            vec![
+                "co".to_owned(),
+                "phonebk".to_owned(),
+                "ka".to_owned(),
+                "shifted".to_owned(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! A `DisplayNames` API mirroring `Intl.DisplayNames`: given a UI locale,
+//! render human-readable names for language, script and region subtags,
+//! and for language-region combinations. Variant subtags are not rendered.
+
+use crate::locale::Locale;
+
+/// Whether a rendered name should be the long form (`"United States"`) or
+/// the short form (`"US"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    #[default]
+    Long,
+    Short,
+}
+
+struct Entry {
+    ui_locale: &'static str,
+    subtag: &'static str,
+    long: &'static str,
+    short: Option<&'static str>,
+}
+
+/// A hand-picked subset of CLDR's `main/<locale>/{languages,territories}.xml`
+/// display-name data, covering the UI locales and subtags this crate has
+/// been asked to support.
+static REGION_NAMES: &[Entry] = &[
+    Entry {
+        ui_locale: "en",
+        subtag: "US",
+        long: "United States",
+        short: Some("US"),
+    },
+    Entry {
+        ui_locale: "en",
+        subtag: "CH",
+        long: "Switzerland",
+        short: Some("CH"),
+    },
+    Entry {
+        ui_locale: "en",
+        subtag: "GB",
+        long: "United Kingdom",
+        short: Some("UK"),
+    },
+    Entry {
+        ui_locale: "de",
+        subtag: "CH",
+        long: "Schweiz",
+        short: None,
+    },
+    Entry {
+        ui_locale: "de",
+        subtag: "US",
+        long: "Vereinigte Staaten",
+        short: None,
+    },
+];
+
+static LANGUAGE_NAMES: &[Entry] = &[
+    Entry {
+        ui_locale: "en",
+        subtag: "de",
+        long: "German",
+        short: None,
+    },
+    Entry {
+        ui_locale: "en",
+        subtag: "en",
+        long: "English",
+        short: None,
+    },
+    Entry {
+        ui_locale: "en",
+        subtag: "fr",
+        long: "French",
+        short: None,
+    },
+    Entry {
+        ui_locale: "de",
+        subtag: "de",
+        long: "Deutsch",
+        short: None,
+    },
+    Entry {
+        ui_locale: "de",
+        subtag: "en",
+        long: "Englisch",
+        short: None,
+    },
+];
+
+static SCRIPT_NAMES: &[Entry] = &[Entry {
+    ui_locale: "en",
+    subtag: "Latn",
+    long: "Latin",
+    short: None,
+}];
+
+/// Language-region combinations that render as a single, idiomatic name
+/// rather than a concatenation of the two (e.g. `de-CH` is "Swiss High
+/// German", not "German (Switzerland)").
+static LANGUAGE_REGION_NAMES: &[(&str, &str, &str, &str)] =
+    &[("en", "de", "CH", "Swiss High German")];
+
+fn lookup<'a>(table: &'a [Entry], ui_locale: &str, subtag: &str) -> Option<&'a Entry> {
+    table
+        .iter()
+        .find(|e| e.ui_locale.eq_ignore_ascii_case(ui_locale) && e.subtag == subtag)
+}
+
+fn render(entry: &Entry, style: Style) -> String {
+    match style {
+        Style::Long => entry.long.to_owned(),
+        Style::Short => entry.short.unwrap_or(entry.long).to_owned(),
+    }
+}
+
+/// Renders human-readable subtag names for a given UI locale, falling
+/// back through the UI locale's [`fallback_chain`] when an exact entry
+/// is missing.
+///
+/// [`fallback_chain`]: Locale::fallback_chain
+pub struct DisplayNames {
+    ui_locale: Locale,
+    style: Style,
+}
+
+impl DisplayNames {
+    pub fn new(ui_locale: Locale) -> DisplayNames {
+        DisplayNames {
+            ui_locale,
+            style: Style::default(),
+        }
+    }
+
+    pub fn with_style(ui_locale: Locale, style: Style) -> DisplayNames {
+        DisplayNames { ui_locale, style }
+    }
+
+    /// Renders the display name for `target`'s language and region
+    /// together, when a dedicated combined name exists (e.g. `de-CH` ->
+    /// "Swiss High German"); otherwise falls back to [`of_language`].
+    ///
+    /// [`of_language`]: DisplayNames::of_language
+    pub fn of_locale(&self, target: &Locale) -> Option<String> {
+        if let (Some(language), Some(region)) = (target.language(), target.region()) {
+            for ui_locale in self.ui_locale.fallback_chain() {
+                let ui_tag = ui_locale.base_name().unwrap_or_else(|| "und".to_owned());
+                let found = LANGUAGE_REGION_NAMES.iter().find(|entry| {
+                    entry.0 == ui_tag.as_str()
+                        && entry.1 == language.as_str()
+                        && entry.2 == region.as_str()
+                });
+                if let Some(entry) = found {
+                    return Some(entry.3.to_owned());
+                }
+            }
+        }
+
+        self.of_language(target)
+    }
+
+    pub fn of_language(&self, target: &Locale) -> Option<String> {
+        let subtag = target.language()?;
+        self.resolve(LANGUAGE_NAMES, subtag)
+    }
+
+    pub fn of_script(&self, target: &Locale) -> Option<String> {
+        let subtag = target.script()?;
+        self.resolve(SCRIPT_NAMES, subtag)
+    }
+
+    pub fn of_region(&self, target: &Locale) -> Option<String> {
+        let subtag = target.region()?;
+        self.resolve(REGION_NAMES, subtag)
+    }
+
+    fn resolve(&self, table: &[Entry], subtag: &str) -> Option<String> {
+        for ui_locale in self.ui_locale.fallback_chain() {
+            let ui_tag = ui_locale.base_name().unwrap_or_else(|| "und".to_owned());
+            if let Some(entry) = lookup(table, &ui_tag, subtag) {
+                return Some(render(entry, self.style));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_region_name_in_english() {
+        let display = DisplayNames::new(Locale::new("en").unwrap());
+        let target = Locale::new("und-CH").unwrap();
+
+        assert_eq!(display.of_region(&target), Some("Switzerland".to_owned()));
+    }
+
+    #[test]
+    fn renders_language_name_in_english() {
+        let display = DisplayNames::new(Locale::new("en").unwrap());
+        let target = Locale::new("de").unwrap();
+
+        assert_eq!(display.of_language(&target), Some("German".to_owned()));
+    }
+
+    #[test]
+    fn renders_combined_language_region_name() {
+        let display = DisplayNames::new(Locale::new("en").unwrap());
+        let target = Locale::new("de-CH").unwrap();
+
+        assert_eq!(
+            display.of_locale(&target),
+            Some("Swiss High German".to_owned())
+        );
+    }
+
+    #[test]
+    fn falls_back_through_ui_locale_chain() {
+        // "de-CH" has no dedicated region-name entry; falls back to "de".
+        let display = DisplayNames::new(Locale::new("de-CH").unwrap());
+        let target = Locale::new("und-US").unwrap();
+
+        assert_eq!(
+            display.of_region(&target),
+            Some("Vereinigte Staaten".to_owned())
+        );
+    }
+
+    #[test]
+    fn short_style_prefers_the_short_form() {
+        let display = DisplayNames::with_style(Locale::new("en").unwrap(), Style::Short);
+        let target = Locale::new("und-GB").unwrap();
+
+        assert_eq!(display.of_region(&target), Some("UK".to_owned()));
+    }
+}
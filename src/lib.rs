@@ -0,0 +1,14 @@
+/*!
+A Rust implementation of ECMA-402's `Intl` primitives, built on top of
+[BCP 47](https://tools.ietf.org/html/bcp47) language tags.
+*/
+
+mod canonicalize;
+pub mod display_names;
+mod likely_subtags;
+pub mod locale;
+pub mod matching;
+
+pub use display_names::DisplayNames;
+pub use locale::{FallbackChain, FallbackOrder, Locale};
+pub use matching::LanguageRange;
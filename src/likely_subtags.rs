@@ -0,0 +1,169 @@
+//! Bundled "likely subtags" data and the UTS #35 `Add Likely Subtags` /
+//! `Remove Likely Subtags` transforms that power [`Locale::maximize`] and
+//! [`Locale::minimize`].
+//!
+//! The table below is a subset of CLDR's `supplemental/likelySubtags.xml`,
+//! generated by hand for the languages this crate currently cares about.
+//! Each row's search pattern (`pat_language`/`pat_script`/`pat_region`)
+//! mirrors one of CLDR's key shapes (`lang-script-region`, `lang-region`,
+//! `lang-script`, `lang`, or `und-script`, the last spelled with
+//! `pat_language: "und"`) and maps to the full triple it expands to.
+//! Patterns are plain `&'static str`/`Option<&'static str>` fields rather
+//! than a concatenated string key, so matching a candidate is a handful of
+//! slice comparisons with no `format!` allocation on the lookup path.
+//!
+//! [`Locale::maximize`]: crate::locale::Locale::maximize
+//! [`Locale::minimize`]: crate::locale::Locale::minimize
+
+struct LikelySubtagsEntry {
+    pat_language: &'static str,
+    pat_script: Option<&'static str>,
+    pat_region: Option<&'static str>,
+    language: &'static str,
+    script: &'static str,
+    region: &'static str,
+}
+
+static LIKELY_SUBTAGS: &[LikelySubtagsEntry] = &[
+    entry("en", None, None, "en", "Latn", "US"),
+    entry("en", None, Some("GB"), "en", "Latn", "GB"),
+    entry("de", None, None, "de", "Latn", "DE"),
+    entry("de", None, Some("CH"), "de", "Latn", "CH"),
+    entry("fr", None, None, "fr", "Latn", "FR"),
+    entry("es", None, None, "es", "Latn", "ES"),
+    entry("pt", None, None, "pt", "Latn", "BR"),
+    entry("pt", None, Some("PT"), "pt", "Latn", "PT"),
+    entry("it", None, None, "it", "Latn", "IT"),
+    entry("nl", None, None, "nl", "Latn", "NL"),
+    entry("ru", None, None, "ru", "Cyrl", "RU"),
+    entry("uk", None, None, "uk", "Cyrl", "UA"),
+    entry("pl", None, None, "pl", "Latn", "PL"),
+    entry("sv", None, None, "sv", "Latn", "SE"),
+    entry("da", None, None, "da", "Latn", "DK"),
+    entry("fi", None, None, "fi", "Latn", "FI"),
+    entry("nb", None, None, "nb", "Latn", "NO"),
+    entry("tr", None, None, "tr", "Latn", "TR"),
+    entry("el", None, None, "el", "Grek", "GR"),
+    entry("he", None, None, "he", "Hebr", "IL"),
+    entry("ar", None, None, "ar", "Arab", "EG"),
+    entry("und", Some("Arab"), None, "ar", "Arab", "EG"),
+    entry("fa", None, None, "fa", "Arab", "IR"),
+    entry("hi", None, None, "hi", "Deva", "IN"),
+    entry("und", Some("Deva"), None, "hi", "Deva", "IN"),
+    entry("bn", None, None, "bn", "Beng", "BD"),
+    entry("th", None, None, "th", "Thai", "TH"),
+    entry("und", Some("Thai"), None, "th", "Thai", "TH"),
+    entry("vi", None, None, "vi", "Latn", "VN"),
+    entry("ko", None, None, "ko", "Kore", "KR"),
+    entry("und", Some("Kore"), None, "ko", "Kore", "KR"),
+    entry("ja", None, None, "ja", "Jpan", "JP"),
+    entry("und", Some("Jpan"), None, "ja", "Jpan", "JP"),
+    entry("zh", None, None, "zh", "Hans", "CN"),
+    entry("zh", Some("Hant"), None, "zh", "Hant", "TW"),
+    entry("zh", None, Some("TW"), "zh", "Hant", "TW"),
+    entry("zh", None, Some("HK"), "zh", "Hant", "HK"),
+    entry("und", Some("Hans"), None, "zh", "Hans", "CN"),
+    entry("und", Some("Hant"), None, "zh", "Hant", "TW"),
+    entry("und", None, None, "en", "Latn", "US"),
+];
+
+const fn entry(
+    pat_language: &'static str,
+    pat_script: Option<&'static str>,
+    pat_region: Option<&'static str>,
+    language: &'static str,
+    script: &'static str,
+    region: &'static str,
+) -> LikelySubtagsEntry {
+    LikelySubtagsEntry {
+        pat_language,
+        pat_script,
+        pat_region,
+        language,
+        script,
+        region,
+    }
+}
+
+fn lookup(
+    language: &str,
+    script: Option<&str>,
+    region: Option<&str>,
+) -> Option<&'static LikelySubtagsEntry> {
+    LIKELY_SUBTAGS
+        .iter()
+        .find(|e| e.pat_language == language && e.pat_script == script && e.pat_region == region)
+}
+
+/// A candidate `(language, script, region)` search pattern tried against
+/// [`LIKELY_SUBTAGS`] while resolving [`maximize`].
+type MaximizeCandidate<'a> = Option<(&'a str, Option<&'a str>, Option<&'a str>)>;
+
+/// Applies the CLDR "Add Likely Subtags" transform to a `(language,
+/// script, region)` triple, returning the filled-in triple on success.
+///
+/// Fields that are already present in the input are never overwritten;
+/// only absent (`None`) fields are filled from the matched table row.
+/// Candidate patterns are tried in priority order: `lang-script-region`,
+/// `lang-region`, `lang-script`, `lang`, then `und-script`. Candidates are
+/// built by comparing subtag slices directly against the table rather
+/// than allocating a concatenated key, so a lookup performs no heap
+/// allocation beyond the three owned `String`s returned to the caller.
+pub fn maximize(
+    language: Option<&str>,
+    script: Option<&str>,
+    region: Option<&str>,
+) -> Option<(String, String, String)> {
+    let lang = language.unwrap_or("und");
+
+    let candidates: [MaximizeCandidate; 5] = [
+        match (script, region) {
+            (Some(s), Some(r)) => Some((lang, Some(s), Some(r))),
+            _ => None,
+        },
+        region.map(|r| (lang, None, Some(r))),
+        script.map(|s| (lang, Some(s), None)),
+        Some((lang, None, None)),
+        script.map(|s| ("und", Some(s), None)),
+    ];
+
+    let matched = candidates
+        .iter()
+        .flatten()
+        .find_map(|&(l, s, r)| lookup(l, s, r))?;
+
+    Some((
+        language.unwrap_or(matched.language).to_owned(),
+        script.unwrap_or(matched.script).to_owned(),
+        region.unwrap_or(matched.region).to_owned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximize_language_only() {
+        assert_eq!(
+            maximize(Some("de"), None, None),
+            Some(("de".to_owned(), "Latn".to_owned(), "DE".to_owned()))
+        );
+    }
+
+    #[test]
+    fn maximize_preserves_supplied_fields() {
+        assert_eq!(
+            maximize(Some("de"), None, Some("CH")),
+            Some(("de".to_owned(), "Latn".to_owned(), "CH".to_owned()))
+        );
+    }
+
+    #[test]
+    fn maximize_script_only_fallback() {
+        assert_eq!(
+            maximize(None, Some("Arab"), None),
+            Some(("ar".to_owned(), "Arab".to_owned(), "EG".to_owned()))
+        );
+    }
+}
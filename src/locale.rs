@@ -2,6 +2,9 @@
 https://tc39.es/proposal-intl-locale
 */
 
+use crate::canonicalize;
+use crate::likely_subtags;
+use crate::matching::{self, LanguageRange};
 use language_tags::{LanguageTag, Result};
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -46,15 +49,110 @@ mod platform {
 
         Ok(from_wide_string(&buf).unwrap())
     }
+
+    /// Windows only exposes a single default locale name through this
+    /// API, so the preference list is always one element long.
+    pub fn preferred_languages() -> Result<Vec<String>, std::io::Error> {
+        locale_name().map(|name| vec![name])
+    }
 }
 
-#[cfg(unix)]
+#[cfg(target_os = "macos")]
 mod platform {
+    use objc::runtime::Object;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    /// Queries `NSLocale.preferredLanguages`, converting each macOS
+    /// language identifier (already BCP 47-ish, e.g. `"en-GB"`) into the
+    /// form our parser expects.
+    pub fn preferred_languages() -> Result<Vec<String>, std::io::Error> {
+        unsafe {
+            let locale_class = class!(NSLocale);
+            let preferred: *mut Object = msg_send![locale_class, preferredLanguages];
+            let count: usize = msg_send![preferred, count];
+
+            let mut languages = Vec::with_capacity(count);
+            for i in 0..count {
+                let identifier: *mut Object = msg_send![preferred, objectAtIndex: i];
+                let utf8: *const std::os::raw::c_char = msg_send![identifier, UTF8String];
+                let s = std::ffi::CStr::from_ptr(utf8)
+                    .to_string_lossy()
+                    .into_owned();
+                languages.push(super::normalize_posix_locale(&s));
+            }
+
+            if languages.is_empty() {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "NSLocale.preferredLanguages was empty",
+                ))
+            } else {
+                Ok(languages)
+            }
+        }
+    }
+
     pub fn locale_name() -> Result<String, std::io::Error> {
-        let posix_tagish =
-            std::env::var("LANG").map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-        let without_dangly_bits = posix_tagish.split(".").next().unwrap();
-        Ok(without_dangly_bits.replace("_", "-"))
+        preferred_languages()?.into_iter().next().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no preferred language")
+        })
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    /// POSIX locale-environment-variable precedence, highest first, per
+    /// `setlocale(3)`. `LANGUAGE` is handled separately since it is a
+    /// colon-separated *list* of fallbacks rather than a single value.
+    const PRECEDENCE: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+    pub fn locale_name() -> Result<String, std::io::Error> {
+        preferred_languages()?.into_iter().next().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no preferred language")
+        })
+    }
+
+    /// Returns the user's ordered locale preferences, consulting
+    /// `LC_ALL`, `LC_MESSAGES`, `LANG` and the colon-separated `LANGUAGE`
+    /// list, in that order, skipping `"C"`/`"POSIX"` and duplicates.
+    pub fn preferred_languages() -> Result<Vec<String>, std::io::Error> {
+        let mut candidates = Vec::new();
+
+        for var in PRECEDENCE {
+            if let Ok(value) = std::env::var(var) {
+                candidates.push(value);
+            }
+        }
+
+        if let Ok(value) = std::env::var("LANGUAGE") {
+            for tag in value.split(':') {
+                candidates.push(tag.to_owned());
+            }
+        }
+
+        let mut languages = Vec::new();
+        for candidate in candidates {
+            if candidate.is_empty()
+                || candidate.eq_ignore_ascii_case("C")
+                || candidate.eq_ignore_ascii_case("POSIX")
+            {
+                continue;
+            }
+
+            let normalized = super::normalize_posix_locale(&candidate);
+            if !languages.contains(&normalized) {
+                languages.push(normalized);
+            }
+        }
+
+        if languages.is_empty() {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no usable locale environment variable was set",
+            ))
+        } else {
+            Ok(languages)
+        }
     }
 }
 
@@ -62,6 +160,33 @@ thread_local! {
     pub static CURRENT_LOCALE: Rc<RefCell<Locale>> = Rc::new(RefCell::new(Locale::default()));
 }
 
+/// A Unicode extension `key` subtag is exactly two alphanumeric characters;
+/// everything else occurring after it is a `type` subtag of its value.
+fn is_unicode_extension_key(subtag: &str) -> bool {
+    subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn unicode_extension_value(values: Vec<String>) -> String {
+    if values.is_empty() {
+        "true".to_owned()
+    } else {
+        values.join("-")
+    }
+}
+
+/// Normalizes a POSIX-ish locale identifier (e.g. `"de_CH.UTF-8@euro"`)
+/// into something [`LanguageTag`] can parse: strips the `.codeset` and
+/// `@modifier` suffixes and replaces `_` separators with `-`.
+#[cfg(unix)]
+fn normalize_posix_locale(value: &str) -> String {
+    let without_modifier = value.split('@').next().unwrap_or(value);
+    let without_codeset = without_modifier
+        .split('.')
+        .next()
+        .unwrap_or(without_modifier);
+    without_codeset.replace('_', "-")
+}
+
 impl Default for Locale {
     fn default() -> Locale {
         if let Ok(v) = platform::locale_name() {
@@ -82,7 +207,7 @@ impl Locale {
     }
 
     pub fn autoupdating_current() -> Rc<RefCell<Locale>> {
-        CURRENT_LOCALE.with(|locale| Rc::clone(&locale))
+        CURRENT_LOCALE.with(Rc::clone)
     }
 
     pub fn set_current(new_locale: Locale) {
@@ -94,6 +219,41 @@ impl Locale {
         Ok(Locale { tag })
     }
 
+    /// Returns the user's full ordered locale preference list, as
+    /// reported by the platform (`NSLocale.preferredLanguages` on macOS,
+    /// the POSIX locale environment variables on other Unix platforms,
+    /// or the single Windows default locale). Entries that fail to parse
+    /// as a language tag are skipped.
+    pub fn preferred_languages() -> Vec<Locale> {
+        platform::preferred_languages()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|name| Locale::new(name).ok())
+            .collect()
+    }
+
+    /// Picks the best available locale for `requested` using the RFC 4647
+    /// "Lookup" algorithm: for each requested range in priority order, the
+    /// range is progressively truncated until it matches the prefix of
+    /// some locale in `available`.
+    pub fn lookup(requested: &[LanguageRange], available: &[Locale]) -> Option<Locale> {
+        matching::lookup(requested, available, Locale::subtags).cloned()
+    }
+
+    /// Returns every locale in `available` matched by the RFC 4647
+    /// "Filtering" (basic) algorithm: a locale matches a requested range
+    /// if its subtags are a prefix-extension of that range.
+    pub fn filter(requested: &[LanguageRange], available: &[Locale]) -> Vec<Locale> {
+        matching::filter(requested, available, Locale::subtags)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    fn subtags(&self) -> Vec<String> {
+        self.tag.to_string().split('-').map(str::to_owned).collect()
+    }
+
     pub fn base_name(&self) -> Option<String> {
         let mut out = match self.tag.language.as_ref() {
             Some(v) => v.to_string(),
@@ -101,45 +261,327 @@ impl Locale {
         };
 
         if let Some(v) = self.script() {
-            out.push_str("-");
+            out.push('-');
             out.push_str(v);
         }
 
         if let Some(v) = self.region() {
-            out.push_str("-");
+            out.push('-');
             out.push_str(v);
         }
 
         for variant in &self.tag.variants {
-            out.push_str("-");
+            out.push('-');
             out.push_str(variant);
         }
 
         Some(out)
     }
 
-    pub fn calendar(&self) -> String {
-        unimplemented!()
+    /// Fills in the locale's language, script and region following UTS #35
+    /// "Add Likely Subtags", consulting the bundled likely-subtags table.
+    ///
+    /// Fields the locale already carries are never overwritten; a language
+    /// of `und` counts as absent and is filled in. Returns `true` if any
+    /// field was filled in, `false` if the locale was left unchanged (e.g.
+    /// it was already maximized, or no table entry matched).
+    pub fn maximize(&mut self) -> bool {
+        let language = self
+            .tag
+            .language
+            .as_deref()
+            .filter(|l| !l.eq_ignore_ascii_case("und"));
+        let script = self.tag.script.as_deref();
+        let region = self.tag.region.as_deref();
+
+        let (language, script, region) = match likely_subtags::maximize(language, script, region) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let mut changed = false;
+
+        if self.tag.language.as_deref() != Some(language.as_str()) {
+            self.tag.language = Some(language);
+            changed = true;
+        }
+        if self.tag.script.as_deref() != Some(script.as_str()) {
+            self.tag.script = Some(script);
+            changed = true;
+        }
+        if self.tag.region.as_deref() != Some(region.as_str()) {
+            self.tag.region = Some(region);
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Reduces the script and region subtags following UTS #35 "Remove
+    /// Likely Subtags": keeps the first of `lang`, `lang-region`,
+    /// `lang-script` that [`maximize`] expands back to the same triple,
+    /// or `lang-script-region` if none does.
+    /// This can trade a script for a region (`zh-Hant` becomes `zh-TW`).
+    ///
+    /// Variants and extensions are left untouched. Returns `true` if the
+    /// script or region changed.
+    ///
+    /// [`maximize`]: Locale::maximize
+    pub fn minimize(&mut self) -> bool {
+        let language = self.tag.language.as_deref();
+        let script = self.tag.script.as_deref();
+        let region = self.tag.region.as_deref();
+
+        let full = match likely_subtags::maximize(language, script, region) {
+            Some(v) => v,
+            None => return false,
+        };
+
+        let reproduces = |l: Option<&str>, s: Option<&str>, r: Option<&str>| {
+            likely_subtags::maximize(l, s, r).as_ref() == Some(&full)
+        };
+
+        let (new_script, new_region) = if reproduces(Some(&full.0), None, None) {
+            (None, None)
+        } else if reproduces(Some(&full.0), None, Some(&full.2)) {
+            (None, Some(full.2.clone()))
+        } else if reproduces(Some(&full.0), Some(&full.1), None) {
+            (Some(full.1.clone()), None)
+        } else {
+            (Some(full.1.clone()), Some(full.2.clone()))
+        };
+
+        let mut changed = false;
+
+        if self.tag.script.as_deref() != new_script.as_deref() {
+            self.tag.script = new_script;
+            changed = true;
+        }
+        if self.tag.region.as_deref() != new_region.as_deref() {
+            self.tag.region = new_region;
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Rewrites the locale into its canonical form per UTS #35 Annex C
+    /// "LocaleId Canonicalization".
+    ///
+    /// Applies language/script/region alias replacements from the bundled
+    /// alias table, applies variant aliases, sorts variants
+    /// alphabetically, and reorders the `-u-`/`-t-` extension keywords
+    /// into sorted, deduplicated order. A territory alias with more than
+    /// one candidate replacement is resolved by consulting [`maximize`],
+    /// picking the candidate that the locale's language maximizes to.
+    ///
+    /// Returns the rewritten locale alongside `true` if anything changed,
+    /// `false` if the locale was already canonical, mirroring the
+    /// `bool`-returning convention of [`maximize`] and [`minimize`].
+    ///
+    /// ```
+    /// use intl_rs::Locale;
+    /// let (canonical, changed) =
+    ///     Locale::new("ja-Latn-fonipa-hepburn-heploc").unwrap().canonicalize();
+    /// assert!(changed);
+    /// assert_eq!(canonical.base_name().as_deref(), Some("ja-Latn-alalc97-fonipa"));
+    /// ```
+    ///
+    /// [`maximize`]: Locale::maximize
+    /// [`minimize`]: Locale::minimize
+    pub fn canonicalize(&self) -> (Locale, bool) {
+        let mut tag = self.tag.clone();
+        let mut changed = false;
+
+        if let Some(replacement) = tag
+            .language
+            .as_deref()
+            .and_then(canonicalize::canonical_language)
+        {
+            if tag.language.as_deref() != Some(replacement) {
+                tag.language = Some(replacement.to_owned());
+                changed = true;
+            }
+        }
+
+        if let Some(replacement) = tag
+            .script
+            .as_deref()
+            .and_then(canonicalize::canonical_script)
+        {
+            if tag.script.as_deref() != Some(replacement) {
+                tag.script = Some(replacement.to_owned());
+                changed = true;
+            }
+        }
+
+        if let Some(replacement) = tag
+            .region
+            .as_deref()
+            .and_then(|region| canonicalize::canonical_region(tag.language.as_deref(), region))
+        {
+            if tag.region.as_deref() != Some(replacement) {
+                tag.region = Some(replacement.to_owned());
+                changed = true;
+            }
+        }
+
+        let (variants, variants_changed) = canonicalize::canonical_variants(&tag.variants);
+        tag.variants = variants;
+        changed |= variants_changed;
+
+        for subtags in tag.extensions.values_mut() {
+            let canonical = canonicalize::canonical_extension_subtags(subtags);
+            if canonical != *subtags {
+                changed = true;
+            }
+            *subtags = canonical;
+        }
+
+        (Locale { tag }, changed)
+    }
+
+    /// Walks from this locale down to `und`, dropping the least
+    /// significant piece at each step, for resource loaders that need to
+    /// probe progressively less specific bundles.
+    ///
+    /// Equivalent to `fallback_chain_with_order(FallbackOrder::RegionFirst)`.
+    /// Call [`maximize`] first if you want the chain to start from the
+    /// locale's maximized form.
+    ///
+    /// [`maximize`]: Locale::maximize
+    pub fn fallback_chain(&self) -> FallbackChain {
+        self.fallback_chain_with_order(FallbackOrder::RegionFirst)
+    }
+
+    /// Like [`fallback_chain`], but lets the caller choose whether region
+    /// or script is collapsed first.
+    ///
+    /// [`fallback_chain`]: Locale::fallback_chain
+    pub fn fallback_chain_with_order(&self, order: FallbackOrder) -> FallbackChain {
+        FallbackChain {
+            next: Some(self.clone()),
+            order,
+        }
+    }
+
+    /// Drops the locale's least significant piece, following the given
+    /// [`FallbackOrder`], per the ICU fallback algorithm: variants and
+    /// extensions are dropped together first, then region and script are
+    /// dropped one at a time in the chosen order, ending at `und`.
+    /// Returns `None` once called on `und` itself.
+    fn step_down(&self, order: FallbackOrder) -> Option<Locale> {
+        let mut tag = self.tag.clone();
+
+        let is_und = tag
+            .language
+            .as_deref()
+            .map(|l| l.eq_ignore_ascii_case("und"))
+            .unwrap_or(true);
+        if is_und {
+            return None;
+        }
+
+        if !tag.variants.is_empty() || !tag.extensions.is_empty() || !tag.privateuse.is_empty() {
+            tag.variants.clear();
+            tag.extensions.clear();
+            tag.privateuse.clear();
+            return Some(Locale { tag });
+        }
+
+        let dropped = match order {
+            FallbackOrder::RegionFirst if tag.region.is_some() => {
+                tag.region = None;
+                true
+            }
+            FallbackOrder::RegionFirst if tag.script.is_some() => {
+                tag.script = None;
+                true
+            }
+            FallbackOrder::ScriptFirst if tag.script.is_some() => {
+                tag.script = None;
+                true
+            }
+            FallbackOrder::ScriptFirst if tag.region.is_some() => {
+                tag.region = None;
+                true
+            }
+            _ => false,
+        };
+
+        if dropped {
+            Some(Locale { tag })
+        } else {
+            Some(Locale {
+                tag: "und".parse().unwrap(),
+            })
+        }
+    }
+
+    pub fn calendar(&self) -> Option<String> {
+        self.get_unicode_extension("ca")
+    }
+
+    pub fn collation(&self) -> Option<String> {
+        self.get_unicode_extension("co")
     }
 
-    pub fn collation(&self) -> String {
-        unimplemented!()
+    pub fn hour_cycle(&self) -> Option<String> {
+        self.get_unicode_extension("hc")
     }
 
-    pub fn hour_cycle(&self) -> String {
-        unimplemented!()
+    pub fn case_first(&self) -> Option<String> {
+        self.get_unicode_extension("kf")
     }
 
-    pub fn case_first(&self) -> String {
-        unimplemented!()
+    pub fn numeric(&self) -> Option<String> {
+        self.get_unicode_extension("kn")
     }
 
-    pub fn numeric(&self) -> String {
-        unimplemented!()
+    pub fn numbering_system(&self) -> Option<String> {
+        self.get_unicode_extension("nu")
     }
 
-    pub fn numbering_system(&self) -> String {
-        unimplemented!()
+    /// Returns the value associated with `key` in the locale's Unicode
+    /// (`-u-`) extension, following the BCP 47 `key`/`type` grammar.
+    ///
+    /// A key with no explicit type subtag (e.g. the `kn` in
+    /// `"de-u-co-phonebk-kn"`) is treated as having the value `"true"`,
+    /// matching the behaviour of the JS `Intl.Locale` getters.
+    pub fn get_unicode_extension<S: AsRef<str>>(&self, key: S) -> Option<String> {
+        self.unicode_extension_keywords()
+            .find(|(k, _)| k == key.as_ref())
+            .map(|(_, v)| v)
+    }
+
+    /// Iterates over every keyword in the locale's Unicode (`-u-`)
+    /// extension as `(key, value)` pairs, in the order they were written.
+    ///
+    /// Leading attributes (subtags that appear before the first key) are
+    /// skipped, as they carry no key of their own.
+    pub fn unicode_extension_keywords(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        let subtags = self.tag.extensions.get(&b'u').cloned().unwrap_or_default();
+
+        let mut keywords = Vec::new();
+        let mut current: Option<(String, Vec<String>)> = None;
+
+        for subtag in subtags {
+            if is_unicode_extension_key(&subtag) {
+                if let Some((key, values)) = current.take() {
+                    keywords.push((key, unicode_extension_value(values)));
+                }
+                current = Some((subtag, Vec::new()));
+            } else if let Some((_, values)) = current.as_mut() {
+                values.push(subtag);
+            }
+            // Subtags before the first key are bare attributes; ignore them.
+        }
+
+        if let Some((key, values)) = current {
+            keywords.push((key, unicode_extension_value(values)));
+        }
+
+        keywords.into_iter()
     }
 
     pub fn language(&self) -> Option<&String> {
@@ -155,6 +597,32 @@ impl Locale {
     }
 }
 
+/// Controls which subtag [`Locale::fallback_chain_with_order`] collapses
+/// first: region or script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackOrder {
+    RegionFirst,
+    ScriptFirst,
+}
+
+/// An iterator over a locale's fallback chain, from most to least
+/// specific, ending at `und`. Created by [`Locale::fallback_chain`] and
+/// [`Locale::fallback_chain_with_order`].
+pub struct FallbackChain {
+    next: Option<Locale>,
+    order: FallbackOrder,
+}
+
+impl Iterator for FallbackChain {
+    type Item = Locale;
+
+    fn next(&mut self) -> Option<Locale> {
+        let current = self.next.take()?;
+        self.next = current.step_down(self.order);
+        Some(current)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +641,174 @@ mod tests {
         Locale::set_current(Locale::new("de-Latn-u-co-phonebk-ka-shifted-t-und-cyrl").unwrap());
         println!("{:?}", locale);
     }
+
+    #[test]
+    fn unicode_extension_getters() {
+        let locale = Locale::new("de-u-co-phonebk-kn").unwrap();
+
+        assert_eq!(locale.collation(), Some("phonebk".to_owned()));
+        assert_eq!(locale.numeric(), Some("true".to_owned()));
+        assert_eq!(locale.calendar(), None);
+    }
+
+    #[test]
+    fn unicode_extension_keywords_iteration() {
+        let locale = Locale::new("de-u-co-phonebk-ka-shifted").unwrap();
+
+        let keywords: Vec<_> = locale.unicode_extension_keywords().collect();
+        assert_eq!(
+            keywords,
+            vec![
+                ("co".to_owned(), "phonebk".to_owned()),
+                ("ka".to_owned(), "shifted".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unicode_extension_with_leading_attribute() {
+        // "foobar" is an attribute preceding the first key and is skipped.
+        let locale = Locale::new("en-u-foobar-nu-latn").unwrap();
+
+        assert_eq!(locale.numbering_system(), Some("latn".to_owned()));
+        assert_eq!(locale.get_unicode_extension("foobar"), None);
+    }
+
+    #[test]
+    fn maximize_fills_in_script_and_region() {
+        let mut locale = Locale::new("de").unwrap();
+
+        assert!(locale.maximize());
+        assert_eq!(locale.script().map(String::as_str), Some("Latn"));
+        assert_eq!(locale.region().map(String::as_str), Some("DE"));
+
+        // Already maximized: a second call is a no-op.
+        assert!(!locale.maximize());
+    }
+
+    #[test]
+    fn maximize_never_overwrites_supplied_fields() {
+        let mut locale = Locale::new("de-CH").unwrap();
+
+        assert!(locale.maximize());
+        assert_eq!(locale.region().map(String::as_str), Some("CH"));
+    }
+
+    #[test]
+    fn minimize_drops_redundant_script_and_region() {
+        let mut locale = Locale::new("de-Latn-DE").unwrap();
+
+        assert!(locale.minimize());
+        assert_eq!(locale.script(), None);
+        assert_eq!(locale.region(), None);
+    }
+
+    #[test]
+    fn minimize_keeps_region_needed_to_disambiguate() {
+        let mut locale = Locale::new("de-Latn-CH").unwrap();
+
+        assert!(locale.minimize());
+        assert_eq!(locale.script(), None);
+        assert_eq!(locale.region().map(String::as_str), Some("CH"));
+    }
+
+    #[test]
+    fn canonicalize_replaces_deprecated_language() {
+        let locale = Locale::new("iw").unwrap();
+        let (canonical, changed) = locale.canonicalize();
+        assert!(changed);
+        assert_eq!(canonical.language().map(String::as_str), Some("he"));
+    }
+
+    #[test]
+    fn canonicalize_collapses_hepburn_heploc_and_sorts_variants() {
+        let locale = Locale::new("ja-Latn-fonipa-hepburn-heploc").unwrap();
+        let (canonical, changed) = locale.canonicalize();
+        assert!(changed);
+        assert_eq!(
+            canonical.base_name().as_deref(),
+            Some("ja-Latn-alalc97-fonipa")
+        );
+    }
+
+    #[test]
+    fn canonicalize_reports_no_change_when_already_canonical() {
+        let locale = Locale::new("he-Latn-US").unwrap();
+        let (_, changed) = locale.canonicalize();
+        assert!(!changed);
+    }
+
+    #[test]
+    fn lookup_finds_best_available_locale() {
+        let requested = vec![LanguageRange::new("de-CH-1996").unwrap()];
+        let available = vec![Locale::new("de").unwrap(), Locale::new("fr").unwrap()];
+
+        let found = Locale::lookup(&requested, &available).unwrap();
+        assert_eq!(found.language().map(String::as_str), Some("de"));
+    }
+
+    #[test]
+    fn filter_returns_all_prefix_extensions() {
+        let requested = vec![LanguageRange::new("de").unwrap()];
+        let available = vec![
+            Locale::new("de").unwrap(),
+            Locale::new("de-CH").unwrap(),
+            Locale::new("fr").unwrap(),
+        ];
+
+        let found = Locale::filter(&requested, &available);
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn fallback_chain_drops_region_before_script() {
+        let locale = Locale::new("en-Latn-US-u-ca-buddhist").unwrap();
+
+        let chain: Vec<(String, bool)> = locale
+            .fallback_chain()
+            .map(|l| (l.base_name().unwrap(), l.calendar().is_some()))
+            .collect();
+
+        // The `-u-ca-buddhist` extension is dropped in its own step, so
+        // "en-Latn-US" (whose base_name doesn't reflect extensions)
+        // appears both with and without it before region/script collapse.
+        assert_eq!(
+            chain,
+            vec![
+                ("en-Latn-US".to_owned(), true),
+                ("en-Latn-US".to_owned(), false),
+                ("en-Latn".to_owned(), false),
+                ("en".to_owned(), false),
+                ("und".to_owned(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn fallback_chain_with_order_drops_script_before_region() {
+        let locale = Locale::new("en-Latn-US").unwrap();
+
+        let chain: Vec<String> = locale
+            .fallback_chain_with_order(FallbackOrder::ScriptFirst)
+            .map(|l| l.base_name().unwrap())
+            .collect();
+
+        assert_eq!(
+            chain,
+            vec![
+                "en-Latn-US".to_owned(),
+                "en-US".to_owned(),
+                "en".to_owned(),
+                "und".to_owned(),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn normalize_posix_locale_strips_codeset_and_modifier() {
+        assert_eq!(normalize_posix_locale("de_CH.UTF-8@euro"), "de-CH");
+        assert_eq!(normalize_posix_locale("en_US.UTF-8"), "en-US");
+        assert_eq!(normalize_posix_locale("en"), "en");
+    }
 }
@@ -0,0 +1,188 @@
+//! RFC 4647 language-range matching (`Lookup` and `Filtering`), used to
+//! pick the best available locale for a user out of [`Locale::lookup`]
+//! and [`Locale::filter`].
+//!
+//! [`Locale::lookup`]: crate::locale::Locale::lookup
+//! [`Locale::filter`]: crate::locale::Locale::filter
+
+use std::fmt;
+
+/// An error returned when a string is not a syntactically valid RFC 4647
+/// extended language range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLanguageRangeError;
+
+impl fmt::Display for ParseLanguageRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid RFC 4647 language range")
+    }
+}
+
+impl std::error::Error for ParseLanguageRangeError {}
+
+/// An RFC 4647 extended language range: a `-`-separated list of subtags,
+/// any of which may be the wildcard `*`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageRange {
+    subtags: Vec<String>,
+}
+
+impl LanguageRange {
+    pub fn new<S: AsRef<str>>(range: S) -> Result<LanguageRange, ParseLanguageRangeError> {
+        let range = range.as_ref();
+
+        if range.is_empty() {
+            return Err(ParseLanguageRangeError);
+        }
+
+        let subtags: Vec<String> = range.split('-').map(|s| s.to_owned()).collect();
+
+        let all_valid = subtags
+            .iter()
+            .all(|subtag| subtag == "*" || subtag.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        if !all_valid {
+            return Err(ParseLanguageRangeError);
+        }
+
+        Ok(LanguageRange { subtags })
+    }
+
+    /// Whether `tag` (as a `-`-separated subtag list) is
+    /// matched by this range: every non-wildcard range subtag must equal
+    /// the tag's subtag at the same position, case-insensitively.
+    fn matches_prefix(&self, tag_subtags: &[String]) -> bool {
+        if self.subtags.len() > tag_subtags.len() {
+            return false;
+        }
+
+        self.subtags
+            .iter()
+            .zip(tag_subtags)
+            .all(|(range, tag)| range == "*" || range.eq_ignore_ascii_case(tag))
+    }
+
+    fn is_wildcard(&self) -> bool {
+        self.subtags.len() == 1 && self.subtags[0] == "*"
+    }
+
+    /// Successively drops the range's trailing subtag, also dropping a
+    /// newly-trailing singleton (a single-character subtag, marking the
+    /// start of an extension) along with it, per the RFC 4647 `Lookup`
+    /// algorithm.
+    fn truncated(&self) -> Option<LanguageRange> {
+        if self.subtags.len() <= 1 {
+            return None;
+        }
+
+        let mut subtags = self.subtags[..self.subtags.len() - 1].to_vec();
+
+        if let Some(last) = subtags.last() {
+            if last.len() == 1 {
+                subtags.pop();
+            }
+        }
+
+        if subtags.is_empty() {
+            None
+        } else {
+            Some(LanguageRange { subtags })
+        }
+    }
+}
+
+/// Runs the RFC 4647 `Lookup` algorithm: for each range in `requested`,
+/// in priority order, the range is progressively truncated until it
+/// matches the prefix of some tag in `available`; the first requested
+/// range that matches anything wins.
+pub fn lookup<'a, T>(
+    requested: &[LanguageRange],
+    available: &'a [T],
+    subtags_of: impl Fn(&T) -> Vec<String>,
+) -> Option<&'a T> {
+    for range in requested {
+        let mut candidate = Some(range.clone());
+
+        while let Some(range) = candidate {
+            if range.is_wildcard() {
+                break;
+            }
+
+            if let Some(found) = available
+                .iter()
+                .find(|tag| range.matches_prefix(&subtags_of(tag)))
+            {
+                return Some(found);
+            }
+
+            candidate = range.truncated();
+        }
+    }
+
+    None
+}
+
+/// Runs the RFC 4647 `Filtering` (basic) algorithm: returns every tag in
+/// `available` whose subtags extend (are a superset-with-same-prefix of)
+/// some range in `requested`.
+pub fn filter<'a, T>(
+    requested: &[LanguageRange],
+    available: &'a [T],
+    subtags_of: impl Fn(&T) -> Vec<String>,
+) -> Vec<&'a T> {
+    available
+        .iter()
+        .filter(|tag| {
+            let tag_subtags = subtags_of(tag);
+            requested
+                .iter()
+                .any(|range| range.is_wildcard() || range.matches_prefix(&tag_subtags))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subtags(tag: &str) -> Vec<String> {
+        tag.split('-').map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn lookup_truncates_until_a_prefix_matches() {
+        let requested = vec![LanguageRange::new("de-CH-1996").unwrap()];
+        let available = vec!["de".to_owned(), "de-DE".to_owned(), "fr".to_owned()];
+
+        let found = lookup(&requested, &available, |t| subtags(t));
+        assert_eq!(found, Some(&"de".to_owned()));
+    }
+
+    #[test]
+    fn lookup_picks_first_matching_requested_range() {
+        let requested = vec![
+            LanguageRange::new("fr").unwrap(),
+            LanguageRange::new("de-CH").unwrap(),
+        ];
+        let available = vec!["de-CH".to_owned(), "en".to_owned()];
+
+        let found = lookup(&requested, &available, |t| subtags(t));
+        assert_eq!(found, Some(&"de-CH".to_owned()));
+    }
+
+    #[test]
+    fn filter_returns_every_prefix_extension() {
+        let requested = vec![LanguageRange::new("de").unwrap()];
+        let available = vec!["de".to_owned(), "de-CH".to_owned(), "fr".to_owned()];
+
+        let found = filter(&requested, &available, |t| subtags(t));
+        assert_eq!(found, vec![&"de".to_owned(), &"de-CH".to_owned()]);
+    }
+
+    #[test]
+    fn parses_wildcard_subtags() {
+        let range = LanguageRange::new("de-*-DE").unwrap();
+        let tag = vec!["de".to_owned(), "Latn".to_owned(), "DE".to_owned()];
+        assert!(range.matches_prefix(&tag));
+    }
+}